@@ -1,8 +1,74 @@
-use ark_ff::{PrimeField, UniformRand, Zero};
-use decaf377::Fr;
 use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroize;
 
+use crate::ciphersuite::{self, Ciphersuite, Element, Scalar, SerializationError};
+use crate::participant::ParticipantIndex;
 use crate::schnorr;
+use crate::signing_key::SigningKey;
+
+/// Errors that can arise while running the distributed key generation.
+#[derive(Debug, thiserror::Error)]
+pub enum DkgError {
+    /// A signer dealt a secret share that does not match its broadcast commitment.
+    #[error("signer {0} dealt an invalid secret share")]
+    InvalidShare(u64),
+}
+
+/// Errors that can arise while repairing a lost signing share.
+#[derive(Debug, thiserror::Error)]
+pub enum RepairError {
+    /// The recovered share does not match the participant's public verification share.
+    #[error("recovered share does not match participant {0}'s verification share")]
+    Mismatch(u64),
+    /// The helper set is smaller than the threshold, so the share cannot be interpolated.
+    #[error("the helper set is smaller than the threshold")]
+    InsufficientHelpers,
+    /// The helper identifiers and their provided shares do not line up.
+    #[error("helper identifiers and shares have mismatched lengths")]
+    MalformedHelperSet,
+}
+
+/// The pair of single-use nonces `(d_i, e_i)` a signer samples during preprocessing.
+/// These are secret and must never leave the signer.
+pub struct SigningNonces<C: Ciphersuite> {
+    /// The hiding nonce `d_i`.
+    pub hiding: Scalar<C>,
+    /// The binding nonce `e_i`.
+    pub binding: Scalar<C>,
+}
+
+impl<C: Ciphersuite> Zeroize for SigningNonces<C> {
+    fn zeroize(&mut self) {
+        ciphersuite::zeroize_scalar::<C>(&mut self.hiding);
+        ciphersuite::zeroize_scalar::<C>(&mut self.binding);
+    }
+}
+
+impl<C: Ciphersuite> Drop for SigningNonces<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// The public commitments `(D_i, E_i)` broadcast during preprocessing.
+pub struct SigningCommitment<C: Ciphersuite> {
+    /// The signer's identifier.
+    pub id: u64,
+    /// The hiding commitment `D_i = d_i * G`.
+    pub hiding: Element<C>,
+    /// The binding commitment `E_i = e_i * G`.
+    pub binding: Element<C>,
+}
+
+/// A secret share `f_i(j)` dealt by signer `i` to recipient `j` over a private channel.
+pub struct SecretShare<C: Ciphersuite> {
+    /// The identifier of the signer that produced this share.
+    pub from: u64,
+    /// The recipient's identifier, i.e. the evaluation point.
+    pub to: u64,
+    /// The evaluation `f_i(to)` of the sender's polynomial.
+    pub value: Scalar<C>,
+}
 
 /*
 
@@ -50,16 +116,16 @@ use crate::schnorr;
 
 */
 
-pub struct FrostCommittee {
+pub struct FrostCommittee<C: Ciphersuite> {
     /// The number of signers in the committee.
     pub n: usize,
     /// The number of signers required to produce a signature.
     pub t: usize,
     /// The set of signers in the committee.
-    pub signers: Vec<PeerCommitment>,
+    pub signers: Vec<PeerCommitment<C>>,
 }
 
-impl FrostCommittee {
+impl<C: Ciphersuite> FrostCommittee<C> {
     pub fn new(n: usize, t: usize) -> Self {
         Self {
             n,
@@ -68,7 +134,7 @@ impl FrostCommittee {
         }
     }
 
-    pub fn add_signer(&mut self, peer_commitment: PeerCommitment) {
+    pub fn add_signer(&mut self, peer_commitment: PeerCommitment<C>) {
         self.signers.push(peer_commitment);
     }
 
@@ -79,32 +145,131 @@ impl FrostCommittee {
     pub fn committee_size(&self) -> usize {
         self.n
     }
+
+    /// The joint group public key `A = Σ_i commitment_i[0]`, formed from the constant
+    /// terms of every signer's broadcast commitment.
+    pub fn group_public_key(&self) -> Element<C> {
+        self.signers
+            .iter()
+            .fold(ciphersuite::identity::<C>(), |acc, peer| {
+                acc + peer.commitment[0]
+            })
+    }
+
+    /// The public verification share of participant `j`, i.e.
+    /// `Σ_i Σ_k (j^k) * commitment_i[k]`. This is `g * s_j` and lets the committee
+    /// check any single signer's contribution during signing.
+    pub fn verification_share(&self, j: &ParticipantIndex) -> Element<C> {
+        self.signers
+            .iter()
+            .fold(ciphersuite::identity::<C>(), |acc, peer| {
+                acc + FrostSigner::<C>::evaluate_commitment(j.get(), &peer.commitment)
+            })
+    }
+
+    /// The randomized group public key `A' = A + α*G` under which a rerandomized
+    /// signature verifies, without revealing the link back to `A`.
+    pub fn randomized_group_public_key(&self, alpha: Scalar<C>) -> Element<C> {
+        FrostSigner::<C>::randomize_verification_key(self.group_public_key(), alpha)
+    }
+
+    /// The randomized verification share of participant `j`, `g*(s_j + α)`, used to
+    /// pinpoint a misbehaving signer when signing under a randomized key.
+    pub fn randomized_verification_share(
+        &self,
+        j: &ParticipantIndex,
+        alpha: Scalar<C>,
+    ) -> Element<C> {
+        FrostSigner::<C>::randomize_verification_key(self.verification_share(j), alpha)
+    }
+
+    /// Repair the lost signing share of participant `lost` from a helper set `H`, without
+    /// any single helper learning `s_ℓ` or reconstructing the group secret.
+    ///
+    /// `helpers[k]` holds share `helper_shares[k]`. Each helper blinds `λ_i * s_i` into
+    /// `|H|` random summands, routes one to each helper, every helper sums what it
+    /// receives, and `lost` adds the partials. The recovered share is checked against its
+    /// public verification share `g*s_ℓ`.
+    pub fn repair_share<R: CryptoRng + RngCore>(
+        &self,
+        helpers: &[ParticipantIndex],
+        helper_shares: &[Scalar<C>],
+        lost: &ParticipantIndex,
+        mut rng: R,
+    ) -> Result<Scalar<C>, RepairError> {
+        if helpers.len() != helper_shares.len() {
+            return Err(RepairError::MalformedHelperSet);
+        }
+        if helpers.len() < self.t {
+            return Err(RepairError::InsufficientHelpers);
+        }
+
+        // Row `i` holds the summands helper `i` sends to each helper in `H`.
+        let routed: Vec<Vec<Scalar<C>>> = helpers
+            .iter()
+            .zip(helper_shares)
+            .map(|(helper, share)| {
+                let lambda = FrostSigner::<C>::lagrange_coefficient_at(helper, helpers, lost);
+                FrostSigner::<C>::repair_summands(*share, lambda, helpers.len(), &mut rng)
+            })
+            .collect();
+
+        // Column `j` is everything helper `j` receives; it forwards the sum to `lost`.
+        let partials: Vec<Scalar<C>> = (0..helpers.len())
+            .map(|j| {
+                let received: Vec<Scalar<C>> = routed.iter().map(|row| row[j]).collect();
+                FrostSigner::<C>::repair_combine(&received)
+            })
+            .collect();
+
+        let recovered = FrostSigner::<C>::repair_recover(&partials);
+        let expected = self.verification_share(lost);
+        if ciphersuite::mul::<C>(recovered, ciphersuite::generator::<C>()) == expected {
+            Ok(recovered)
+        } else {
+            Err(RepairError::Mismatch(lost.get()))
+        }
+    }
+
+    /// Verify every share in `shares` against its dealer's commitment, attributing blame
+    /// to the first signer whose share fails the Feldman check or who has no commitment
+    /// in the committee.
+    pub fn verify_shares(&self, shares: &[SecretShare<C>]) -> Result<(), DkgError> {
+        for share in shares {
+            let dealer = self
+                .signers
+                .iter()
+                .find(|peer| peer.id == share.from)
+                .ok_or(DkgError::InvalidShare(share.from))?;
+            FrostSigner::<C>::verify_share(share, &dealer.commitment)?;
+        }
+        Ok(())
+    }
 }
 
 /// A participant in the FROST protocol
-pub struct FrostSigner {
+pub struct FrostSigner<C: Ciphersuite> {
     /// The client's identifier.
     id: u64,
-    /// The coefficient of the polynomial used to generate the signing key.
-    /// TODO(erwan): newtype this or something.
-    pub signing_key: Vec<decaf377::Fr>,
+    /// The coefficients of the polynomial used to generate the signing key.
+    pub signing_key: SigningKey<C>,
     /// This participant's blinding factor.
-    pub nonce: decaf377::Fr,
+    pub nonce: Scalar<C>,
     /// A public commitment to the coefficients of the polynomial
     /// used to generate the signing key.
-    pub commitment: Vec<decaf377::Element>,
+    pub commitment: Vec<Element<C>>,
 }
 
-pub struct PeerCommitment {
+pub struct PeerCommitment<C: Ciphersuite> {
     /// The signer's identifier.
     pub id: u64,
     /// A public commitment to the coefficients of that signer's key.
-    pub commitment: Vec<decaf377::Element>,
+    pub commitment: Vec<Element<C>>,
     /// A proof of knowledge of the constant term of the polynomial.
-    pub sig: schnorr::Signature,
+    pub sig: schnorr::Signature<C>,
 }
 
-impl Default for PeerCommitment {
+impl<C: Ciphersuite> Default for PeerCommitment<C> {
     fn default() -> Self {
         Self {
             id: 0,
@@ -114,7 +279,73 @@ impl Default for PeerCommitment {
     }
 }
 
-impl FrostSigner {
+impl<C: Ciphersuite> PeerCommitment<C> {
+    /// The canonical wire encoding: the 8-byte identifier, a 4-byte coefficient count,
+    /// the compressed commitment coefficients, and the 64-byte proof-of-knowledge.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12 + self.commitment.len() * 32 + 64);
+        bytes.extend_from_slice(&self.id.to_le_bytes());
+        bytes.extend_from_slice(&(self.commitment.len() as u32).to_le_bytes());
+        for coefficient in &self.commitment {
+            bytes.extend_from_slice(&ciphersuite::compress::<C>(*coefficient));
+        }
+        bytes.extend_from_slice(&self.sig.to_bytes());
+        bytes
+    }
+
+    /// Decode a peer commitment, validating the declared length against the payload and
+    /// every element and scalar it contains.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
+        if bytes.len() < 12 {
+            return Err(SerializationError::Length {
+                expected: 12,
+                got: bytes.len(),
+            });
+        }
+        let id = u64::from_le_bytes(bytes[..8].try_into().expect("8-byte slice"));
+        let count = u32::from_le_bytes(bytes[8..12].try_into().expect("4-byte slice")) as usize;
+
+        let expected = 12 + count * 32 + 64;
+        if bytes.len() != expected {
+            return Err(SerializationError::Length {
+                expected,
+                got: bytes.len(),
+            });
+        }
+
+        let mut commitment = Vec::with_capacity(count);
+        for chunk in bytes[12..12 + count * 32].chunks_exact(32) {
+            let mut encoded = [0u8; 32];
+            encoded.copy_from_slice(chunk);
+            commitment.push(
+                ciphersuite::decompress::<C>(&encoded).ok_or(SerializationError::InvalidElement)?,
+            );
+        }
+
+        let sig = schnorr::Signature::<C>::from_bytes(&bytes[12 + count * 32..])?;
+        Ok(Self {
+            id,
+            commitment,
+            sig,
+        })
+    }
+}
+
+impl<C: Ciphersuite> Zeroize for FrostSigner<C> {
+    fn zeroize(&mut self) {
+        // `self.signing_key` is a `SigningKey<C>`, which zeroizes its own coefficients
+        // via its own `Drop` impl when this struct's fields are dropped.
+        ciphersuite::zeroize_scalar::<C>(&mut self.nonce);
+    }
+}
+
+impl<C: Ciphersuite> Drop for FrostSigner<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C: Ciphersuite> FrostSigner<C> {
     /// TODO(erwan): this signature is confusing
     pub fn new<R: CryptoRng + RngCore>(
         id: u64,
@@ -122,18 +353,15 @@ impl FrostSigner {
         threshold: usize,
         mut rng: R,
     ) -> Self {
-        let signing_key: Vec<decaf377::Fr> = (0..threshold)
-            .map(|_| decaf377::Fr::rand(&mut rng))
-            .collect::<Vec<_>>();
-
-        let generator = decaf377::basepoint();
+        let signing_key = SigningKey {
+            coefficients: (0..threshold)
+                .map(|_| ciphersuite::random_scalar::<C, _>(&mut rng))
+                .collect::<Vec<_>>(),
+        };
 
-        let commitment: Vec<decaf377::Element> = signing_key
-            .iter()
-            .map(|k| k * generator)
-            .collect::<Vec<_>>();
+        let commitment = signing_key.to_public_signing_key().coefficients;
 
-        let nonce = decaf377::Fr::rand(&mut rng);
+        let nonce = ciphersuite::random_scalar::<C, _>(&mut rng);
 
         Self {
             id,
@@ -147,31 +375,24 @@ impl FrostSigner {
         self.id
     }
 
-    pub fn public_commitment(&self) -> Vec<decaf377::Element> {
+    pub fn public_commitment(&self) -> Vec<Element<C>> {
         self.commitment.clone()
     }
 
-    fn signing_key_evaluate<T: Into<decaf377::Fr>>(&self, point: T) -> decaf377::Fr {
-        let point: decaf377::Fr = point.into();
-        let mut result = decaf377::Fr::zero();
-
-        for coefficient in self.signing_key.iter().rev() {
-            result = result * point + coefficient;
-        }
-
-        result
+    fn signing_key_evaluate(&self, point: Scalar<C>) -> Scalar<C> {
+        self.signing_key.evaluate(point)
     }
 
     /// TODO: specify the transcript protocol in a trait, and implement it for specific
     /// FrostSigner<T: State> types.
-    pub fn keygen_proof(&self) -> (schnorr::Signature, merlin::Transcript) {
-        let nonce_commitment = self.nonce * decaf377::basepoint();
+    pub fn keygen_proof(&self) -> (schnorr::Signature<C>, merlin::Transcript) {
+        let nonce_commitment = ciphersuite::mul::<C>(self.nonce, ciphersuite::generator::<C>());
         let (challenge, transcript) =
-            FrostSigner::keygen_challenge(self.id(), self.commitment[0], nonce_commitment);
+            Self::keygen_challenge(self.id(), self.commitment[0], nonce_commitment);
 
         let sig = schnorr::Signature {
             commitment: nonce_commitment,
-            challenge_response: self.nonce + challenge * self.signing_key[0],
+            challenge_response: self.nonce + challenge * self.signing_key.coefficients[0],
         };
 
         (sig, transcript)
@@ -179,68 +400,396 @@ impl FrostSigner {
 
     pub fn keygen_challenge(
         id: u64,
-        constant_term_commitment: decaf377::Element,
-        nonce_commitment: decaf377::Element,
-    ) -> (decaf377::Fr, merlin::Transcript) {
+        constant_term_commitment: Element<C>,
+        nonce_commitment: Element<C>,
+    ) -> (Scalar<C>, merlin::Transcript) {
         let mut transcript =
-            FrostSigner::keygen_transcript(id, constant_term_commitment, nonce_commitment);
-        let mut challenge_raw: [u8; 32] = [0u8; 32];
-        transcript.challenge_bytes(b"schnorr-sig-challenge", &mut challenge_raw);
-        let challenge_scalar = decaf377::Fr::from_le_bytes_mod_order(&challenge_raw);
+            Self::keygen_transcript(id, constant_term_commitment, nonce_commitment);
+        let challenge_scalar = C::hash_to_scalar(&mut transcript, b"schnorr-sig-challenge");
         (challenge_scalar, transcript)
     }
 
     pub fn keygen_transcript(
         id: u64,
-        constant_term_commitment: decaf377::Element,
-        nonce_commitment: decaf377::Element,
+        constant_term_commitment: Element<C>,
+        nonce_commitment: Element<C>,
     ) -> merlin::Transcript {
         let mut transcript = merlin::Transcript::new(b"freddo377-key-gen");
         transcript.append_u64(b"signer-identifier", id);
         transcript.append_message(
             b"constant-term-commitment",
-            constant_term_commitment.vartime_compress().0.as_ref(),
+            ciphersuite::compress::<C>(constant_term_commitment).as_ref(),
         );
 
         transcript.append_message(
             b"nonce-commitment",
-            nonce_commitment.vartime_compress().0.as_ref(),
+            ciphersuite::compress::<C>(nonce_commitment).as_ref(),
         );
         transcript
     }
 
     pub fn keygen_verify_proof(
         id: u64,
-        constant_term_commitment: decaf377::Element,
-        nonce_commitment: decaf377::Element,
-        sig: schnorr::Signature,
+        constant_term_commitment: Element<C>,
+        nonce_commitment: Element<C>,
+        sig: schnorr::Signature<C>,
     ) -> bool {
         let (challenge, _) =
-            FrostSigner::keygen_challenge(id, constant_term_commitment, nonce_commitment);
+            Self::keygen_challenge(id, constant_term_commitment, nonce_commitment);
+
+        let candidate_commitment = ciphersuite::mul::<C>(sig.challenge_response, ciphersuite::generator::<C>())
+            - ciphersuite::mul::<C>(challenge, constant_term_commitment);
+        ciphersuite::compress::<C>(candidate_commitment) == ciphersuite::compress::<C>(sig.commitment)
+    }
+
+    pub fn nonce_commitment(&self) -> Element<C> {
+        ciphersuite::mul::<C>(self.nonce, ciphersuite::generator::<C>())
+    }
+
+    /// Round 2 of keygen: evaluate this signer's polynomial at every recipient's
+    /// identifier, producing the secret shares that are sent over private channels.
+    pub fn deal_shares(&self, recipients: &[ParticipantIndex]) -> Vec<SecretShare<C>> {
+        recipients
+            .iter()
+            .map(|j| SecretShare {
+                from: self.id(),
+                to: j.get(),
+                value: self.signing_key_evaluate(ciphersuite::scalar_from_u64::<C>(j.get())),
+            })
+            .collect()
+    }
+
+    /// Verify a received secret share against the dealer's broadcast Feldman commitment:
+    /// `g * f_i(j) == Σ_{k} (j^k) * commitment_i[k]`. On mismatch, blame is attributed to
+    /// the signer that dealt the share.
+    pub fn verify_share(
+        share: &SecretShare<C>,
+        commitment: &[Element<C>],
+    ) -> Result<(), DkgError> {
+        let expected = ciphersuite::mul::<C>(share.value, ciphersuite::generator::<C>());
+        let actual = Self::evaluate_commitment(share.to, commitment);
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(DkgError::InvalidShare(share.from))
+        }
+    }
+
+    /// Evaluate a broadcast commitment vector at point `j` in the exponent:
+    /// `Σ_{k} (j^k) * commitment[k]`.
+    fn evaluate_commitment(point: u64, commitment: &[Element<C>]) -> Element<C> {
+        let point = ciphersuite::scalar_from_u64::<C>(point);
+        let mut result = ciphersuite::identity::<C>();
+        let mut power = ciphersuite::scalar_one::<C>();
+        for term in commitment.iter() {
+            result = result + ciphersuite::mul::<C>(power, *term);
+            power *= point;
+        }
+        result
+    }
+
+    /// Combine the shares addressed to this participant into its long-term signing
+    /// share `s_j = Σ_i f_i(j)`. Callers are expected to have verified each share first.
+    pub fn aggregate_signing_share(shares: &[SecretShare<C>]) -> Scalar<C> {
+        shares
+            .iter()
+            .fold(ciphersuite::scalar_zero::<C>(), |acc, share| {
+                acc + share.value
+            })
+    }
+
+    /// Round 1 of signing (preprocessing): sample the single-use nonces `(d_i, e_i)` and
+    /// publish their commitments `(D_i, E_i)`.
+    pub fn preprocess<R: CryptoRng + RngCore>(
+        &self,
+        mut rng: R,
+    ) -> (SigningNonces<C>, SigningCommitment<C>) {
+        let hiding = ciphersuite::random_scalar::<C, _>(&mut rng);
+        let binding = ciphersuite::random_scalar::<C, _>(&mut rng);
+        let generator = ciphersuite::generator::<C>();
+        (
+            SigningNonces { hiding, binding },
+            SigningCommitment {
+                id: self.id(),
+                hiding: ciphersuite::mul::<C>(hiding, generator),
+                binding: ciphersuite::mul::<C>(binding, generator),
+            },
+        )
+    }
+
+    /// The per-signer binding factor `ρ_i = H(i, m, B)`, where `B` is the list of all
+    /// round-1 commitments. Built with the same merlin-transcript pattern as keygen.
+    pub fn binding_factor(
+        id: u64,
+        message: &[u8],
+        commitments: &[SigningCommitment<C>],
+    ) -> Scalar<C> {
+        let mut transcript = merlin::Transcript::new(b"freddo377-signing-binding");
+        transcript.append_u64(b"signer-identifier", id);
+        transcript.append_message(b"message", message);
+        for commitment in commitments {
+            transcript.append_u64(b"commitment-identifier", commitment.id);
+            transcript.append_message(
+                b"hiding-commitment",
+                ciphersuite::compress::<C>(commitment.hiding).as_ref(),
+            );
+            transcript.append_message(
+                b"binding-commitment",
+                ciphersuite::compress::<C>(commitment.binding).as_ref(),
+            );
+        }
+        C::hash_to_scalar(&mut transcript, b"binding-factor")
+    }
 
-        let candidate_commitment =
-            sig.challenge_response * decaf377::basepoint() - challenge * constant_term_commitment;
-        candidate_commitment.vartime_compress().0 == sig.commitment.vartime_compress().0
+    /// The group commitment `R = Σ_{i∈S} (D_i + ρ_i*E_i)`.
+    pub fn group_commitment(
+        commitments: &[SigningCommitment<C>],
+        message: &[u8],
+    ) -> Element<C> {
+        commitments
+            .iter()
+            .fold(ciphersuite::identity::<C>(), |acc, commitment| {
+                let rho = Self::binding_factor(commitment.id, message, commitments);
+                acc + commitment.hiding + ciphersuite::mul::<C>(rho, commitment.binding)
+            })
     }
 
-    pub fn nonce_commitment(&self) -> decaf377::Element {
-        self.nonce * decaf377::basepoint()
+    /// The signing challenge `c = H(R, group_pubkey, m)`.
+    pub fn signing_challenge(
+        group_commitment: Element<C>,
+        group_pubkey: Element<C>,
+        message: &[u8],
+    ) -> Scalar<C> {
+        let mut transcript = merlin::Transcript::new(b"freddo377-signing-challenge");
+        transcript.append_message(
+            b"group-commitment",
+            ciphersuite::compress::<C>(group_commitment).as_ref(),
+        );
+        transcript.append_message(
+            b"group-public-key",
+            ciphersuite::compress::<C>(group_pubkey).as_ref(),
+        );
+        transcript.append_message(b"message", message);
+        C::hash_to_scalar(&mut transcript, b"challenge")
+    }
+
+    /// The Lagrange coefficient `λ_i = Π_{j∈S, j≠i} j/(j-i)` for interpolating the set
+    /// `S` at the origin.
+    pub fn lagrange_coefficient(i: &ParticipantIndex, set: &[ParticipantIndex]) -> Scalar<C> {
+        let xi = ciphersuite::scalar_from_u64::<C>(i.get());
+        let mut numerator = ciphersuite::scalar_one::<C>();
+        let mut denominator = ciphersuite::scalar_one::<C>();
+        for j in set {
+            if j.get() == i.get() {
+                continue;
+            }
+            let xj = ciphersuite::scalar_from_u64::<C>(j.get());
+            numerator *= xj;
+            denominator *= xj - xi;
+        }
+        numerator
+            * ciphersuite::scalar_invert::<C>(denominator)
+                .expect("signing set has distinct identifiers")
+    }
+
+    /// The generalized Lagrange coefficient that interpolates `set` at an arbitrary
+    /// `point`: `λ_i = Π_{j∈set, j≠i} (point - j)/(i - j)`. Evaluating at the origin
+    /// recovers [`FrostSigner::lagrange_coefficient`].
+    pub fn lagrange_coefficient_at(
+        i: &ParticipantIndex,
+        set: &[ParticipantIndex],
+        point: &ParticipantIndex,
+    ) -> Scalar<C> {
+        let xi = ciphersuite::scalar_from_u64::<C>(i.get());
+        let xp = ciphersuite::scalar_from_u64::<C>(point.get());
+        let mut numerator = ciphersuite::scalar_one::<C>();
+        let mut denominator = ciphersuite::scalar_one::<C>();
+        for j in set {
+            if j.get() == i.get() {
+                continue;
+            }
+            let xj = ciphersuite::scalar_from_u64::<C>(j.get());
+            numerator *= xp - xj;
+            denominator *= xi - xj;
+        }
+        numerator
+            * ciphersuite::scalar_invert::<C>(denominator)
+                .expect("helper set has distinct identifiers")
+    }
+
+    /// Step 1 of repair, run by helper `i`: split `λ_i * s_i` into `helper_count` random
+    /// additive summands that sum back to `λ_i * s_i`, one destined for each helper in
+    /// `H`. No single summand reveals anything about `s_i`.
+    pub fn repair_summands<R: CryptoRng + RngCore>(
+        signing_share: Scalar<C>,
+        lambda: Scalar<C>,
+        helper_count: usize,
+        mut rng: R,
+    ) -> Vec<Scalar<C>> {
+        let target = lambda * signing_share;
+        let mut summands: Vec<Scalar<C>> = (0..helper_count.saturating_sub(1))
+            .map(|_| ciphersuite::random_scalar::<C, _>(&mut rng))
+            .collect();
+        let blinded = summands
+            .iter()
+            .fold(ciphersuite::scalar_zero::<C>(), |acc, s| acc + *s);
+        summands.push(target - blinded);
+        summands
+    }
+
+    /// Step 2 of repair, run by each helper: sum the summands routed to it from every
+    /// helper into a single partial value, which is then forwarded to `ℓ`.
+    pub fn repair_combine(received: &[Scalar<C>]) -> Scalar<C> {
+        received
+            .iter()
+            .fold(ciphersuite::scalar_zero::<C>(), |acc, s| acc + *s)
+    }
+
+    /// Step 3 of repair, run by `ℓ`: add all helpers' partials to recover
+    /// `s_ℓ = Σ_{i∈H} λ_i * s_i`.
+    pub fn repair_recover(partials: &[Scalar<C>]) -> Scalar<C> {
+        partials
+            .iter()
+            .fold(ciphersuite::scalar_zero::<C>(), |acc, s| acc + *s)
+    }
+
+    /// Round 2 of signing: produce this signer's response
+    /// `z_i = d_i + ρ_i*e_i + λ_i * s_i * c`, where `s_i` is the DKG long-term share.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign(
+        &self,
+        nonces: &SigningNonces<C>,
+        signing_share: Scalar<C>,
+        lambda: Scalar<C>,
+        commitments: &[SigningCommitment<C>],
+        group_pubkey: Element<C>,
+        message: &[u8],
+    ) -> Scalar<C> {
+        let rho = Self::binding_factor(self.id(), message, commitments);
+        let r = Self::group_commitment(commitments, message);
+        let c = Self::signing_challenge(r, group_pubkey, message);
+        nonces.hiding + rho * nonces.binding + lambda * signing_share * c
+    }
+
+    /// Verify a single signature share before aggregation so a malicious signer can be
+    /// pinpointed: `z_i*G == (D_i + ρ_i*E_i) + c*λ_i*verification_share_i`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_signature_share(
+        commitment: &SigningCommitment<C>,
+        response: Scalar<C>,
+        verification_share: Element<C>,
+        lambda: Scalar<C>,
+        commitments: &[SigningCommitment<C>],
+        group_pubkey: Element<C>,
+        message: &[u8],
+    ) -> bool {
+        let rho = Self::binding_factor(commitment.id, message, commitments);
+        let r = Self::group_commitment(commitments, message);
+        let c = Self::signing_challenge(r, group_pubkey, message);
+        let lhs = ciphersuite::mul::<C>(response, ciphersuite::generator::<C>());
+        let rhs = commitment.hiding
+            + ciphersuite::mul::<C>(rho, commitment.binding)
+            + ciphersuite::mul::<C>(c * lambda, verification_share);
+        lhs == rhs
+    }
+
+    /// Aggregate the signature shares into a single `schnorr::Signature`.
+    pub fn aggregate(
+        responses: &[Scalar<C>],
+        commitments: &[SigningCommitment<C>],
+        message: &[u8],
+    ) -> schnorr::Signature<C> {
+        let z = responses
+            .iter()
+            .fold(ciphersuite::scalar_zero::<C>(), |acc, z_i| acc + *z_i);
+        schnorr::Signature {
+            commitment: Self::group_commitment(commitments, message),
+            challenge_response: z,
+        }
+    }
+
+    /// Derive the per-use randomizer `α` deterministically from public inputs, so every
+    /// signer agrees on it without an extra communication round.
+    pub fn derive_randomizer(group_pubkey: Element<C>, message: &[u8]) -> Scalar<C> {
+        let mut transcript = merlin::Transcript::new(b"freddo377-randomizer");
+        transcript.append_message(
+            b"group-public-key",
+            ciphersuite::compress::<C>(group_pubkey).as_ref(),
+        );
+        transcript.append_message(b"message", message);
+        C::hash_to_scalar(&mut transcript, b"randomizer")
+    }
+
+    /// Randomize a verification key — either the group key or a single verification
+    /// share — by `α`: `A' = A + α*G`.
+    pub fn randomize_verification_key(key: Element<C>, alpha: Scalar<C>) -> Element<C> {
+        key + ciphersuite::mul::<C>(alpha, ciphersuite::generator::<C>())
+    }
+
+    /// Round 2 under a randomized key: `z_i = d_i + ρ_i*e_i + λ_i*(s_i + α)*c`, with the
+    /// challenge bound to the randomized group key `A' = A + α*G`. Summing the responses
+    /// yields a signature that verifies under `A'` while the stored shares stay fixed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign_randomized(
+        &self,
+        nonces: &SigningNonces<C>,
+        signing_share: Scalar<C>,
+        lambda: Scalar<C>,
+        commitments: &[SigningCommitment<C>],
+        group_pubkey: Element<C>,
+        message: &[u8],
+        alpha: Scalar<C>,
+    ) -> Scalar<C> {
+        let randomized_pubkey = Self::randomize_verification_key(group_pubkey, alpha);
+        let rho = Self::binding_factor(self.id(), message, commitments);
+        let r = Self::group_commitment(commitments, message);
+        let c = Self::signing_challenge(r, randomized_pubkey, message);
+        nonces.hiding + rho * nonces.binding + lambda * (signing_share + alpha) * c
+    }
+
+    /// Verify a single randomized signature share against the randomized verification
+    /// share `g*(s_i + α)`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_signature_share_randomized(
+        commitment: &SigningCommitment<C>,
+        response: Scalar<C>,
+        verification_share: Element<C>,
+        lambda: Scalar<C>,
+        commitments: &[SigningCommitment<C>],
+        group_pubkey: Element<C>,
+        message: &[u8],
+        alpha: Scalar<C>,
+    ) -> bool {
+        let randomized_pubkey = Self::randomize_verification_key(group_pubkey, alpha);
+        let randomized_share = Self::randomize_verification_key(verification_share, alpha);
+        let rho = Self::binding_factor(commitment.id, message, commitments);
+        let r = Self::group_commitment(commitments, message);
+        let c = Self::signing_challenge(r, randomized_pubkey, message);
+        let lhs = ciphersuite::mul::<C>(response, ciphersuite::generator::<C>());
+        let rhs = commitment.hiding
+            + ciphersuite::mul::<C>(rho, commitment.binding)
+            + ciphersuite::mul::<C>(c * lambda, randomized_share);
+        lhs == rhs
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use ark_ff::One;
     use rand_core::OsRng;
 
     use super::*;
+    use crate::ciphersuite::Decaf377Suite;
+
+    type Suite = Decaf377Suite;
 
     #[test]
     /// Check that a signer can verify its own proof.
     pub fn generate_proof_and_verify() {
         let mut rng = OsRng;
-        let signer = FrostSigner::new(1, 3, 2, &mut rng);
+        let signer = FrostSigner::<Suite>::new(1, 3, 2, &mut rng);
         let (sig, _transcript) = signer.keygen_proof();
-        assert!(FrostSigner::keygen_verify_proof(
+        assert!(FrostSigner::<Suite>::keygen_verify_proof(
             signer.id(),
             signer.commitment[0],
             signer.nonce_commitment(),
@@ -252,14 +801,14 @@ mod tests {
     /// Check that challenges are generated deterministically.
     pub fn transcript_consistency() {
         let mut rng = OsRng;
-        let signer = FrostSigner::new(1, 3, 2, &mut rng);
-        let (challenge1, _) = FrostSigner::keygen_challenge(
+        let signer = FrostSigner::<Suite>::new(1, 3, 2, &mut rng);
+        let (challenge1, _) = FrostSigner::<Suite>::keygen_challenge(
             signer.id(),
             signer.commitment[0],
             signer.nonce_commitment(),
         );
 
-        let (challenge2, _) = FrostSigner::keygen_challenge(
+        let (challenge2, _) = FrostSigner::<Suite>::keygen_challenge(
             signer.id(),
             signer.commitment[0],
             signer.nonce_commitment(),
@@ -267,6 +816,307 @@ mod tests {
 
         assert_eq!(challenge1, challenge2);
     }
+
+    #[test]
+    /// Run a small DKG and check that the dealt shares verify, that a tampered share is
+    /// blamed on its dealer, and that the reconstructed verification share matches `g*s_j`.
+    pub fn dkg_share_distribution() {
+        let mut rng = OsRng;
+        let (n, t) = (3, 2);
+
+        let signers: Vec<FrostSigner<Suite>> = (1..=n as u64)
+            .map(|id| FrostSigner::<Suite>::new(id, n, t, &mut rng))
+            .collect();
+
+        let recipients: Vec<ParticipantIndex> =
+            (1..=n as u64).map(ParticipantIndex::new).collect();
+
+        let mut committee = FrostCommittee::<Suite>::new(n, t);
+        for signer in &signers {
+            committee.add_signer(PeerCommitment {
+                id: signer.id(),
+                commitment: signer.public_commitment(),
+                sig: schnorr::Signature::default(),
+            });
+        }
+
+        // Collect the shares addressed to the first recipient and check they verify.
+        let recipient = &recipients[0];
+        let shares: Vec<SecretShare<Suite>> = signers
+            .iter()
+            .map(|signer| signer.deal_shares(std::slice::from_ref(recipient)).remove(0))
+            .collect();
+
+        assert!(committee.verify_shares(&shares).is_ok());
+
+        // The long-term share must agree with the public verification share.
+        let s_j = FrostSigner::<Suite>::aggregate_signing_share(&shares);
+        assert_eq!(
+            s_j * decaf377::basepoint(),
+            committee.verification_share(recipient)
+        );
+
+        // A tampered share is blamed on the signer that dealt it.
+        let mut bad = shares;
+        bad[1].value += decaf377::Fr::one();
+        match committee.verify_shares(&bad) {
+            Err(DkgError::InvalidShare(id)) => assert_eq!(id, signers[1].id()),
+            _ => panic!("expected InvalidShare blame"),
+        }
+    }
+
+    #[test]
+    /// Run the DKG, sign a message with a `t`-sized subset, and check that the
+    /// aggregated signature verifies under the joint group public key.
+    pub fn two_round_signing() {
+        let mut rng = OsRng;
+        let (n, t) = (3, 2);
+
+        let signers: Vec<FrostSigner<Suite>> = (1..=n as u64)
+            .map(|id| FrostSigner::<Suite>::new(id, n, t, &mut rng))
+            .collect();
+        let indices: Vec<ParticipantIndex> =
+            (1..=n as u64).map(ParticipantIndex::new).collect();
+
+        let mut committee = FrostCommittee::<Suite>::new(n, t);
+        for signer in &signers {
+            committee.add_signer(PeerCommitment {
+                id: signer.id(),
+                commitment: signer.public_commitment(),
+                sig: schnorr::Signature::default(),
+            });
+        }
+
+        // Every participant collects and combines the shares dealt to it.
+        let long_term: Vec<decaf377::Fr> = indices
+            .iter()
+            .map(|j| {
+                let shares: Vec<SecretShare<Suite>> = signers
+                    .iter()
+                    .map(|signer| signer.deal_shares(std::slice::from_ref(j)).remove(0))
+                    .collect();
+                FrostSigner::<Suite>::aggregate_signing_share(&shares)
+            })
+            .collect();
+
+        let group_pubkey = committee.group_public_key();
+        let message = b"freddo377 two-round signing";
+
+        // Sign with the subset S = {1, 2}.
+        let set = vec![ParticipantIndex::new(1), ParticipantIndex::new(2)];
+        let participants = [0usize, 1];
+
+        let preprocessed: Vec<(SigningNonces<Suite>, SigningCommitment<Suite>)> = participants
+            .iter()
+            .map(|&i| signers[i].preprocess(&mut rng))
+            .collect();
+        let commitments: Vec<SigningCommitment<Suite>> = preprocessed
+            .iter()
+            .map(|(_, c)| SigningCommitment {
+                id: c.id,
+                hiding: c.hiding,
+                binding: c.binding,
+            })
+            .collect();
+
+        let mut responses = Vec::new();
+        for (slot, &i) in participants.iter().enumerate() {
+            let index = &set[slot];
+            let lambda = FrostSigner::<Suite>::lagrange_coefficient(index, &set);
+            let z_i = signers[i].sign(
+                &preprocessed[slot].0,
+                long_term[i],
+                lambda,
+                &commitments,
+                group_pubkey,
+                message,
+            );
+            assert!(FrostSigner::<Suite>::verify_signature_share(
+                &commitments[slot],
+                z_i,
+                committee.verification_share(index),
+                lambda,
+                &commitments,
+                group_pubkey,
+                message,
+            ));
+            responses.push(z_i);
+        }
+
+        let sig = FrostSigner::<Suite>::aggregate(&responses, &commitments, message);
+        let c = FrostSigner::<Suite>::signing_challenge(sig.commitment, group_pubkey, message);
+        let recomputed = sig.challenge_response * decaf377::basepoint() - c * group_pubkey;
+        assert_eq!(
+            recomputed.vartime_compress().0,
+            sig.commitment.vartime_compress().0
+        );
+    }
+
+    #[test]
+    /// Sign under a deterministically derived randomizer and check the aggregate
+    /// signature verifies under the randomized key `A'` but not under `A`.
+    pub fn rerandomized_signing() {
+        let mut rng = OsRng;
+        let (n, t) = (3, 2);
+
+        let signers: Vec<FrostSigner<Suite>> = (1..=n as u64)
+            .map(|id| FrostSigner::<Suite>::new(id, n, t, &mut rng))
+            .collect();
+        let indices: Vec<ParticipantIndex> =
+            (1..=n as u64).map(ParticipantIndex::new).collect();
+
+        let mut committee = FrostCommittee::<Suite>::new(n, t);
+        for signer in &signers {
+            committee.add_signer(PeerCommitment {
+                id: signer.id(),
+                commitment: signer.public_commitment(),
+                sig: schnorr::Signature::default(),
+            });
+        }
+
+        let long_term: Vec<decaf377::Fr> = indices
+            .iter()
+            .map(|j| {
+                let shares: Vec<SecretShare<Suite>> = signers
+                    .iter()
+                    .map(|signer| signer.deal_shares(std::slice::from_ref(j)).remove(0))
+                    .collect();
+                FrostSigner::<Suite>::aggregate_signing_share(&shares)
+            })
+            .collect();
+
+        let group_pubkey = committee.group_public_key();
+        let message = b"freddo377 rerandomized signing";
+        let alpha = FrostSigner::<Suite>::derive_randomizer(group_pubkey, message);
+        let randomized_pubkey = committee.randomized_group_public_key(alpha);
+
+        let set = vec![ParticipantIndex::new(1), ParticipantIndex::new(2)];
+        let participants = [0usize, 1];
+
+        let preprocessed: Vec<(SigningNonces<Suite>, SigningCommitment<Suite>)> = participants
+            .iter()
+            .map(|&i| signers[i].preprocess(&mut rng))
+            .collect();
+        let commitments: Vec<SigningCommitment<Suite>> = preprocessed
+            .iter()
+            .map(|(_, c)| SigningCommitment {
+                id: c.id,
+                hiding: c.hiding,
+                binding: c.binding,
+            })
+            .collect();
+
+        let mut responses = Vec::new();
+        for (slot, &i) in participants.iter().enumerate() {
+            let index = &set[slot];
+            let lambda = FrostSigner::<Suite>::lagrange_coefficient(index, &set);
+            let z_i = signers[i].sign_randomized(
+                &preprocessed[slot].0,
+                long_term[i],
+                lambda,
+                &commitments,
+                group_pubkey,
+                message,
+                alpha,
+            );
+            assert!(FrostSigner::<Suite>::verify_signature_share_randomized(
+                &commitments[slot],
+                z_i,
+                committee.verification_share(index),
+                lambda,
+                &commitments,
+                group_pubkey,
+                message,
+                alpha,
+            ));
+            responses.push(z_i);
+        }
+
+        let sig = FrostSigner::<Suite>::aggregate(&responses, &commitments, message);
+        let c = FrostSigner::<Suite>::signing_challenge(sig.commitment, randomized_pubkey, message);
+
+        let under_randomized =
+            sig.challenge_response * decaf377::basepoint() - c * randomized_pubkey;
+        assert_eq!(
+            under_randomized.vartime_compress().0,
+            sig.commitment.vartime_compress().0
+        );
+
+        let under_original = sig.challenge_response * decaf377::basepoint() - c * group_pubkey;
+        assert_ne!(
+            under_original.vartime_compress().0,
+            sig.commitment.vartime_compress().0
+        );
+    }
+
+    #[test]
+    /// After a DKG, recover a lost share from a threshold-sized helper set and check it
+    /// matches both the original share and the public verification share.
+    pub fn repair_lost_share() {
+        let mut rng = OsRng;
+        let (n, t) = (3, 2);
+
+        let signers: Vec<FrostSigner<Suite>> = (1..=n as u64)
+            .map(|id| FrostSigner::<Suite>::new(id, n, t, &mut rng))
+            .collect();
+        let indices: Vec<ParticipantIndex> =
+            (1..=n as u64).map(ParticipantIndex::new).collect();
+
+        let mut committee = FrostCommittee::<Suite>::new(n, t);
+        for signer in &signers {
+            committee.add_signer(PeerCommitment {
+                id: signer.id(),
+                commitment: signer.public_commitment(),
+                sig: schnorr::Signature::default(),
+            });
+        }
+
+        let long_term: Vec<decaf377::Fr> = indices
+            .iter()
+            .map(|j| {
+                let shares: Vec<SecretShare<Suite>> = signers
+                    .iter()
+                    .map(|signer| signer.deal_shares(std::slice::from_ref(j)).remove(0))
+                    .collect();
+                FrostSigner::<Suite>::aggregate_signing_share(&shares)
+            })
+            .collect();
+
+        // Participant 3 lost its share; participants 1 and 2 help it recover.
+        let lost = ParticipantIndex::new(3);
+        let helpers = vec![ParticipantIndex::new(1), ParticipantIndex::new(2)];
+        let helper_shares = vec![long_term[0], long_term[1]];
+
+        let recovered = committee
+            .repair_share(&helpers, &helper_shares, &lost, &mut rng)
+            .expect("the helper set should recover the lost share");
+
+        assert_eq!(recovered, long_term[2]);
+        assert_eq!(
+            recovered * decaf377::basepoint(),
+            committee.verification_share(&lost)
+        );
+    }
+
+    #[test]
+    /// A peer commitment survives a byte round-trip, and a truncated encoding is rejected.
+    pub fn peer_commitment_roundtrip() {
+        let mut rng = OsRng;
+        let signer = FrostSigner::<Suite>::new(7, 3, 2, &mut rng);
+        let (sig, _) = signer.keygen_proof();
+        let peer = PeerCommitment::<Suite> {
+            id: signer.id(),
+            commitment: signer.public_commitment(),
+            sig,
+        };
+
+        let bytes = peer.to_bytes();
+        let decoded = PeerCommitment::<Suite>::from_bytes(&bytes).expect("valid encoding");
+        assert_eq!(decoded.id, peer.id);
+        assert_eq!(decoded.to_bytes(), bytes);
+
+        assert!(PeerCommitment::<Suite>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
 }
 /*
 
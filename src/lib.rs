@@ -1,21 +1,16 @@
-use ark_ff::PrimeField;
+mod ciphersuite;
+pub use ciphersuite::{Ciphersuite, Decaf377Suite, Field, Group, SerializationError};
+
 mod frost;
-pub use frost::FrostSigner;
+pub use frost::{
+    DkgError, FrostCommittee, FrostSigner, PeerCommitment, RepairError, SecretShare,
+    SigningCommitment, SigningNonces,
+};
 
 mod schnorr;
-pub use schnorr::Signature;
+pub use schnorr::{BatchError, BatchVerifier, Signature};
 
 mod signing_key;
+pub use signing_key::VerificationKey;
 
-mod keygen;
 mod participant;
-
-struct ParticipantIndex(pub u64);
-
-impl ParticipantIndex {
-    pub fn new(x: u64) -> Self {
-        // TODO: use thiserror
-        assert_ne!(x, 0, "ParticipantIndex must be nonzero");
-        Self(x)
-    }
-}
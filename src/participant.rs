@@ -1,4 +1,4 @@
-struct ParticipantIndex(pub u64);
+pub(crate) struct ParticipantIndex(pub u64);
 
 impl ParticipantIndex {
     pub fn new(x: u64) -> Self {
@@ -6,4 +6,9 @@ impl ParticipantIndex {
         assert_ne!(x, 0, "ParticipantIndex must be nonzero");
         Self(x)
     }
+
+    /// The identifier as a raw `u64`.
+    pub fn get(&self) -> u64 {
+        self.0
+    }
 }
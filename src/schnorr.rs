@@ -1,20 +1,238 @@
-use ark_ff::Zero;
-use decaf377::{Element, Fr};
+use rand_core::{CryptoRng, RngCore};
 
-pub struct Signature {
+use crate::ciphersuite::{self, Ciphersuite, Element, Scalar, SerializationError};
+use crate::frost::FrostSigner;
+
+pub struct Signature<C: Ciphersuite> {
     /// A public commitment to a random scalar.
-    pub commitment: Element,
+    pub commitment: Element<C>,
     /// \mu := k + a0*H(...)
-    pub challenge_response: Fr,
+    pub challenge_response: Scalar<C>,
 }
 
-impl Signature {}
+impl<C: Ciphersuite> Signature<C> {
+    /// The canonical 64-byte encoding: the compressed commitment followed by the
+    /// little-endian challenge response.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&ciphersuite::compress::<C>(self.commitment));
+        bytes[32..].copy_from_slice(&ciphersuite::scalar_to_le_bytes::<C>(self.challenge_response));
+        bytes
+    }
+
+    /// Decode a signature, validating the commitment element and the scalar encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
+        if bytes.len() != 64 {
+            return Err(SerializationError::Length {
+                expected: 64,
+                got: bytes.len(),
+            });
+        }
+        let mut commitment_bytes = [0u8; 32];
+        commitment_bytes.copy_from_slice(&bytes[..32]);
+        let mut response_bytes = [0u8; 32];
+        response_bytes.copy_from_slice(&bytes[32..]);
+
+        let commitment = ciphersuite::decompress::<C>(&commitment_bytes)
+            .ok_or(SerializationError::InvalidElement)?;
+        let challenge_response = ciphersuite::scalar_from_canonical_bytes::<C>(&response_bytes)
+            .ok_or(SerializationError::InvalidScalar)?;
+        Ok(Self {
+            commitment,
+            challenge_response,
+        })
+    }
+}
 
-impl Default for Signature {
+impl<C: Ciphersuite> Default for Signature<C> {
     fn default() -> Self {
         Self {
-            commitment: Element::zero(),
-            challenge_response: Fr::zero(),
+            commitment: ciphersuite::identity::<C>(),
+            challenge_response: ciphersuite::scalar_zero::<C>(),
+        }
+    }
+}
+
+/// Errors returned by [`BatchVerifier`].
+#[derive(Debug, thiserror::Error)]
+pub enum BatchError {
+    /// The aggregate relation did not hold; at least one signature is invalid.
+    #[error("batch verification failed")]
+    Invalid,
+    /// The per-signature fallback pinpointed the invalid entries by queue index.
+    #[error("invalid signatures at indices {0:?}")]
+    InvalidSignatures(Vec<usize>),
+}
+
+/// A single queued proof-of-knowledge signature: the signer `id`, its public key (the
+/// constant term `A_j`), and the `Signature`.
+struct BatchItem<C: Ciphersuite> {
+    id: u64,
+    public_key: Element<C>,
+    signature: Signature<C>,
+}
+
+/// Verifies many Schnorr proofs at once via a random linear combination.
+///
+/// Instead of checking each `z_j*G == R_j + c_j*A_j` individually, the verifier draws a
+/// random nonzero weight `a_j` per signature and checks the single relation
+/// `(Σ_j a_j*z_j)*G == Σ_j a_j*R_j + Σ_j (a_j*c_j)*A_j`. The weights are essential:
+/// without them an adversary could craft two signatures whose errors cancel.
+pub struct BatchVerifier<C: Ciphersuite> {
+    items: Vec<BatchItem<C>>,
+}
+
+impl<C: Ciphersuite> Default for BatchVerifier<C> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<C: Ciphersuite> BatchVerifier<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a signature produced by `keygen_proof` under public key `public_key`.
+    pub fn queue(&mut self, id: u64, public_key: Element<C>, signature: Signature<C>) {
+        self.items.push(BatchItem {
+            id,
+            public_key,
+            signature,
+        });
+    }
+
+    /// Check the whole batch with the random-linear-combination relation.
+    pub fn verify<R: CryptoRng + RngCore>(&self, mut rng: R) -> Result<(), BatchError> {
+        let mut response_accumulator = ciphersuite::scalar_zero::<C>();
+        let mut commitment_weights = Vec::with_capacity(self.items.len());
+        let mut commitments = Vec::with_capacity(self.items.len());
+        let mut public_key_weights = Vec::with_capacity(self.items.len());
+        let mut public_keys = Vec::with_capacity(self.items.len());
+
+        for item in &self.items {
+            let (challenge, _) = FrostSigner::<C>::keygen_challenge(
+                item.id,
+                item.public_key,
+                item.signature.commitment,
+            );
+            let weight = Self::nonzero_weight(&mut rng);
+
+            response_accumulator += weight * item.signature.challenge_response;
+            commitment_weights.push(weight);
+            commitments.push(item.signature.commitment);
+            public_key_weights.push(weight * challenge);
+            public_keys.push(item.public_key);
         }
+
+        // The right-hand side is two multiscalar multiplications rather than
+        // `2 * items.len()` independent `mul`s folded together with `+`; the left-hand
+        // side is a single `mul` of the accumulated response.
+        let rhs = ciphersuite::multiscalar_mul::<C>(&commitment_weights, &commitments)
+            + ciphersuite::multiscalar_mul::<C>(&public_key_weights, &public_keys);
+
+        let lhs = ciphersuite::mul::<C>(response_accumulator, ciphersuite::generator::<C>());
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(BatchError::Invalid)
+        }
+    }
+
+    /// Fallback for when [`BatchVerifier::verify`] fails: verify each queued signature on
+    /// its own and report which ones are invalid.
+    pub fn identify_invalid(&self) -> Result<(), BatchError> {
+        let invalid: Vec<usize> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                !FrostSigner::<C>::keygen_verify_proof(
+                    item.id,
+                    item.public_key,
+                    item.signature.commitment,
+                    Signature {
+                        commitment: item.signature.commitment,
+                        challenge_response: item.signature.challenge_response,
+                    },
+                )
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(BatchError::InvalidSignatures(invalid))
+        }
+    }
+
+    /// Sample a uniformly random nonzero weight.
+    fn nonzero_weight<R: CryptoRng + RngCore>(rng: &mut R) -> Scalar<C> {
+        let zero = ciphersuite::scalar_zero::<C>();
+        loop {
+            let candidate = ciphersuite::random_scalar::<C, _>(rng);
+            if candidate != zero {
+                return candidate;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::ciphersuite::Decaf377Suite;
+
+    type Suite = Decaf377Suite;
+
+    #[test]
+    /// A batch of honestly generated proofs verifies, and flipping one commitment is
+    /// caught by both the aggregate check and the per-signature fallback.
+    pub fn batch_verify_and_identify() {
+        let mut rng = OsRng;
+
+        let signers: Vec<FrostSigner<Suite>> = (1..=4u64)
+            .map(|id| FrostSigner::<Suite>::new(id, 4, 2, &mut rng))
+            .collect();
+
+        let mut batch = BatchVerifier::<Suite>::new();
+        for signer in &signers {
+            let (sig, _) = signer.keygen_proof();
+            batch.queue(signer.id(), signer.commitment[0], sig);
+        }
+        assert!(batch.verify(&mut rng).is_ok());
+        assert!(batch.identify_invalid().is_ok());
+
+        // Corrupt the third signature's commitment.
+        let mut tampered = BatchVerifier::<Suite>::new();
+        for (offset, signer) in signers.iter().enumerate() {
+            let (mut sig, _) = signer.keygen_proof();
+            if offset == 2 {
+                sig.commitment = sig.commitment + decaf377::basepoint();
+            }
+            tampered.queue(signer.id(), signer.commitment[0], sig);
+        }
+        assert!(tampered.verify(&mut rng).is_err());
+        match tampered.identify_invalid() {
+            Err(BatchError::InvalidSignatures(indices)) => assert_eq!(indices, vec![2]),
+            _ => panic!("expected the tampered signature to be identified"),
+        }
+    }
+
+    #[test]
+    /// A signature survives a byte round-trip, and a truncated encoding is rejected.
+    pub fn signature_roundtrip() {
+        let mut rng = OsRng;
+        let signer = FrostSigner::<Suite>::new(1, 3, 2, &mut rng);
+        let (sig, _) = signer.keygen_proof();
+
+        let bytes = sig.to_bytes();
+        let decoded = Signature::<Suite>::from_bytes(&bytes).expect("valid encoding");
+        assert_eq!(sig.to_bytes(), decoded.to_bytes());
+
+        assert!(Signature::<Suite>::from_bytes(&bytes[..63]).is_err());
     }
 }
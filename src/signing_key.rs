@@ -1,36 +1,80 @@
-use ark_ff::{PrimeField, UniformRand, Zero};
+use zeroize::Zeroize;
 
-pub struct VerificationKey {
-    pub coefficients: Vec<decaf377::Element>,
+use crate::ciphersuite::{self, Ciphersuite, Element, Scalar, SerializationError};
+
+pub struct VerificationKey<C: Ciphersuite> {
+    pub coefficients: Vec<Element<C>>,
 }
 
-impl VerificationKey {}
+impl<C: Ciphersuite> VerificationKey<C> {
+    /// The canonical encoding: each commitment coefficient as a 32-byte compressed
+    /// element, concatenated.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.coefficients.len() * 32);
+        for coefficient in &self.coefficients {
+            bytes.extend_from_slice(&ciphersuite::compress::<C>(*coefficient));
+        }
+        bytes
+    }
+
+    /// Decode a verification key, validating the length and every compressed element.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
+        if bytes.len() % 32 != 0 {
+            return Err(SerializationError::Length {
+                expected: bytes.len().next_multiple_of(32),
+                got: bytes.len(),
+            });
+        }
+        let mut coefficients = Vec::with_capacity(bytes.len() / 32);
+        for chunk in bytes.chunks_exact(32) {
+            let mut encoded = [0u8; 32];
+            encoded.copy_from_slice(chunk);
+            let element =
+                ciphersuite::decompress::<C>(&encoded).ok_or(SerializationError::InvalidElement)?;
+            coefficients.push(element);
+        }
+        Ok(Self { coefficients })
+    }
+}
 
-pub struct SigningKey {
-    pub coefficients: Vec<decaf377::Fr>,
+pub struct SigningKey<C: Ciphersuite> {
+    pub coefficients: Vec<Scalar<C>>,
 }
 
-impl SigningKey {
-    pub fn to_public_signing_key(&self) -> VerificationKey {
+impl<C: Ciphersuite> SigningKey<C> {
+    pub fn to_public_signing_key(&self) -> VerificationKey<C> {
         VerificationKey {
             coefficients: self
                 .coefficients
                 .iter()
-                .map(|k| k * decaf377::basepoint())
+                .map(|k| ciphersuite::mul::<C>(*k, ciphersuite::generator::<C>()))
                 .collect::<Vec<_>>(),
         }
     }
 
     /// Evaluate the polynomial at the given point using Horner's method.
     /// TODO: the clashing nomenclature (signing key and polynomial) is awkward.
-    fn evaluate<T: Into<decaf377::Fr>>(&self, point: T) -> decaf377::Fr {
-        let point: decaf377::Fr = point.into();
-        let mut result = decaf377::Fr::zero();
+    pub(crate) fn evaluate(&self, point: Scalar<C>) -> Scalar<C> {
+        let mut result = ciphersuite::scalar_zero::<C>();
 
         for coefficient in self.coefficients.iter().rev() {
-            result = result * point + coefficient;
+            result = result * point + *coefficient;
         }
 
         result
     }
 }
+
+impl<C: Ciphersuite> Zeroize for SigningKey<C> {
+    fn zeroize(&mut self) {
+        for coefficient in &mut self.coefficients {
+            ciphersuite::zeroize_scalar::<C>(coefficient);
+        }
+    }
+}
+
+impl<C: Ciphersuite> Drop for SigningKey<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}